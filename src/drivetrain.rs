@@ -0,0 +1,220 @@
+//! Drivetrain/transmission subsystem tying gearing to the existing OSS/RPM formulas
+use crate::formulas;
+use crate::tire::Tire;
+
+/// A transmission's gear ratios and final drive ratio.
+///
+/// example:
+///
+/// ```
+/// use vehicle::drivetrain::Transmission;
+///
+/// let transmission = Transmission {
+///     gears: vec![2.97, 2.07, 1.43, 1.00, 0.84, 0.67],
+///     final_drive: 3.21,
+/// };
+/// println!("{} gears", transmission.gears.len());
+/// ```
+pub struct Transmission {
+    /// Gear ratios, in order (1st gear first)
+    pub gears: Vec<f64>,
+    /// Final drive (axle) ratio
+    pub final_drive: f64,
+}
+
+impl Transmission {
+    /// Returns the total ratio (gear ratio * final drive) for the given gear index.
+    pub fn total_ratio(&self, gear_index: usize) -> f64 {
+        self.gears[gear_index] * self.final_drive
+    }
+}
+
+/// A complete drivetrain: transmission plus tire, sufficient to convert
+/// between engine RPM and road speed in any gear.
+///
+/// example:
+///
+/// ```
+/// use vehicle::drivetrain::{Drivetrain, Transmission};
+/// use vehicle::tire::Tire;
+///
+/// let drivetrain = Drivetrain {
+///     transmission: Transmission {
+///         gears: vec![2.97, 2.07, 1.43, 1.00, 0.84, 0.67],
+///         final_drive: 3.21,
+///     },
+///     tire: Tire::new("275/55R20"),
+/// };
+/// println!("{} mph", drivetrain.mph_in_gear(0, 6000.0));
+/// ```
+pub struct Drivetrain {
+    pub transmission: Transmission,
+    pub tire: Tire,
+}
+
+impl Drivetrain {
+    /// Calculates road speed in mph for a given gear and engine RPM.
+    pub fn mph_in_gear(&self, gear_index: usize, engine_rpm: f64) -> f64 {
+        let oss = formulas::oss_from_engine_rpm(engine_rpm, self.transmission.gears[gear_index]);
+        formulas::mph_from_oss(oss, self.tire.revs_per_mile(), self.transmission.final_drive)
+    }
+
+    /// Calculates engine RPM for a given gear and road speed (mph).
+    pub fn engine_rpm_in_gear(&self, gear_index: usize, mph: f64) -> f64 {
+        let oss = formulas::oss_from_mph(mph, self.tire.revs_per_mile(), self.transmission.final_drive);
+        formulas::engine_rpm_from_oss(oss, self.transmission.gears[gear_index])
+    }
+
+    /// Calculates the top speed (mph) achievable in a gear at the given redline RPM.
+    pub fn max_speed_in_gear(&self, gear_index: usize, redline_rpm: f64) -> f64 {
+        self.mph_in_gear(gear_index, redline_rpm)
+    }
+
+    /// Returns the mph achieved at each `step` RPM increment (up to `redline`)
+    /// for every gear, one row of samples per gear.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::drivetrain::{Drivetrain, Transmission};
+    /// use vehicle::tire::Tire;
+    ///
+    /// let drivetrain = Drivetrain {
+    ///     transmission: Transmission {
+    ///         gears: vec![2.97, 2.07, 1.43, 1.00, 0.84, 0.67],
+    ///         final_drive: 3.21,
+    ///     },
+    ///     tire: Tire::new("275/55R20"),
+    /// };
+    /// let table = drivetrain.speed_table(7000.0, 1000.0);
+    /// assert_eq!(table.len(), 6);
+    /// ```
+    pub fn speed_table(&self, redline: f64, step: f64) -> Vec<Vec<f64>> {
+        let mut rpm = step;
+        let mut steps = Vec::new();
+        while rpm <= redline {
+            steps.push(rpm);
+            rpm += step;
+        }
+
+        (0..self.transmission.gears.len())
+            .map(|gear_index| {
+                steps
+                    .iter()
+                    .map(|&rpm| self.mph_in_gear(gear_index, rpm))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// For each adjacent gear pair, finds the engine RPM in the current gear
+    /// at which wheel torque (engine torque * total ratio) drops below what
+    /// the next gear would provide at the corresponding (lower) RPM,
+    /// returning the crossover engine RPM for the current gear at each shift.
+    ///
+    /// `torque_curve` is a set of `(rpm, engine_torque)` samples, assumed
+    /// sorted by ascending RPM. Only the sampled RPMs are checked; where none
+    /// of them crosses over, the highest sampled RPM is returned for that
+    /// shift (0.0 for an empty curve).
+    pub fn optimal_shift_points(&self, torque_curve: &[(f64, f64)]) -> Vec<f64> {
+        let wheel_torque = |gear_index: usize, rpm: f64| -> f64 {
+            let engine_torque = interpolate_torque(torque_curve, rpm);
+            engine_torque * self.transmission.total_ratio(gear_index)
+        };
+
+        (0..self.transmission.gears.len().saturating_sub(1))
+            .map(|gear_index| {
+                let mut shift_rpm = torque_curve.last().map(|&(rpm, _)| rpm).unwrap_or(0.0);
+                for &(rpm, _) in torque_curve {
+                    let mph = self.mph_in_gear(gear_index, rpm);
+                    let next_gear_rpm = self.engine_rpm_in_gear(gear_index + 1, mph);
+                    let current_wheel_torque = wheel_torque(gear_index, rpm);
+                    let next_wheel_torque = wheel_torque(gear_index + 1, next_gear_rpm);
+                    if current_wheel_torque < next_wheel_torque {
+                        shift_rpm = rpm;
+                        break;
+                    }
+                }
+                shift_rpm
+            })
+            .collect()
+    }
+}
+
+/// Linearly interpolates engine torque at `rpm` from a sorted `(rpm, torque)` curve.
+fn interpolate_torque(torque_curve: &[(f64, f64)], rpm: f64) -> f64 {
+    if torque_curve.is_empty() {
+        return 0.0;
+    }
+    if rpm <= torque_curve[0].0 {
+        return torque_curve[0].1;
+    }
+    if rpm >= torque_curve[torque_curve.len() - 1].0 {
+        return torque_curve[torque_curve.len() - 1].1;
+    }
+    for window in torque_curve.windows(2) {
+        let (rpm_lo, torque_lo) = window[0];
+        let (rpm_hi, torque_hi) = window[1];
+        if rpm >= rpm_lo && rpm <= rpm_hi {
+            let t = (rpm - rpm_lo) / (rpm_hi - rpm_lo);
+            return torque_lo + t * (torque_hi - torque_lo);
+        }
+    }
+    torque_curve[torque_curve.len() - 1].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_drivetrain() -> Drivetrain {
+        Drivetrain {
+            transmission: Transmission {
+                gears: vec![2.97, 2.07, 1.43, 1.00, 0.84, 0.67],
+                final_drive: 3.21,
+            },
+            tire: Tire::new("275/55R20"),
+        }
+    }
+
+    #[test]
+    fn test_mph_and_rpm_round_trip() {
+        let drivetrain = test_drivetrain();
+        let mph = drivetrain.mph_in_gear(3, 3000.0);
+        let rpm = drivetrain.engine_rpm_in_gear(3, mph);
+        assert!((rpm - 3000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_max_speed_in_gear_increases_with_higher_gears() {
+        let drivetrain = test_drivetrain();
+        let first_gear_top = drivetrain.max_speed_in_gear(0, 7000.0);
+        let top_gear_top = drivetrain.max_speed_in_gear(5, 7000.0);
+        assert!(top_gear_top > first_gear_top);
+    }
+
+    #[test]
+    fn test_speed_table_shape() {
+        let drivetrain = test_drivetrain();
+        let table = drivetrain.speed_table(7000.0, 1000.0);
+        assert_eq!(table.len(), 6);
+        for gear_speeds in &table {
+            assert_eq!(gear_speeds.len(), 7);
+        }
+    }
+
+    #[test]
+    fn test_optimal_shift_points_count() {
+        let drivetrain = test_drivetrain();
+        let torque_curve = vec![
+            (1000.0, 300.0),
+            (2000.0, 350.0),
+            (3000.0, 400.0),
+            (4000.0, 420.0),
+            (5000.0, 380.0),
+            (6000.0, 320.0),
+        ];
+        let shift_points = drivetrain.optimal_shift_points(&torque_curve);
+        assert_eq!(shift_points.len(), drivetrain.transmission.gears.len() - 1);
+    }
+}
@@ -0,0 +1,194 @@
+//! Longitudinal vehicle dynamics: drag, rolling resistance, and acceleration
+
+/// Ambient conditions that affect longitudinal dynamics.
+#[derive(Debug, Clone, Copy)]
+pub struct Environment {
+    /// Air density in kg/m^3
+    pub air_density: f64,
+    /// Acceleration due to gravity in m/s^2
+    pub gravity: f64,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self {
+            air_density: 1.225,
+            gravity: 9.80665,
+        }
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A vehicle's longitudinal performance parameters.
+///
+/// example:
+///
+/// ```
+/// use vehicle::dynamics::{Vehicle, Environment};
+///
+/// let vehicle = Vehicle {
+///     mass: 1500.0,
+///     frontal_area: 2.2,
+///     cd: 0.30,
+///     crr: 0.015,
+///     max_drive_force: 6000.0,
+/// };
+/// let env = Environment::new();
+/// println!("{} m/s^2", vehicle.acceleration(0.0, &env));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Vehicle {
+    /// Mass in kg
+    pub mass: f64,
+    /// Frontal area in m^2
+    pub frontal_area: f64,
+    /// Coefficient of drag
+    pub cd: f64,
+    /// Coefficient of rolling resistance
+    pub crr: f64,
+    /// Maximum force the drivetrain can put to the ground, in Newtons
+    pub max_drive_force: f64,
+}
+
+impl Vehicle {
+    /// Calculates aerodynamic drag force in Newtons at the given speed (m/s).
+    pub fn drag_force(&self, v: f64, env: &Environment) -> f64 {
+        0.5 * env.air_density * self.cd * self.frontal_area * v * v
+    }
+
+    /// Calculates rolling resistance force in Newtons.
+    pub fn rolling_resistance(&self, env: &Environment) -> f64 {
+        self.crr * self.mass * env.gravity
+    }
+
+    /// Calculates the net force in Newtons given a speed (m/s) and drive force (N).
+    pub fn net_force(&self, v: f64, drive_force: f64, env: &Environment) -> f64 {
+        drive_force - self.drag_force(v, env) - self.rolling_resistance(env)
+    }
+
+    /// Calculates acceleration in m/s^2 at the given speed (m/s), using max drive force.
+    pub fn acceleration(&self, v: f64, env: &Environment) -> f64 {
+        self.net_force(v, self.max_drive_force, env) / self.mass
+    }
+
+    /// Solves for top speed (m/s) by Newton iteration on net_force(v) == 0.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::dynamics::{Vehicle, Environment};
+    ///
+    /// let vehicle = Vehicle {
+    ///     mass: 1500.0,
+    ///     frontal_area: 2.2,
+    ///     cd: 0.30,
+    ///     crr: 0.015,
+    ///     max_drive_force: 1200.0,
+    /// };
+    /// let env = Environment::new();
+    /// let top_speed = vehicle.top_speed(&env);
+    /// println!("{} m/s", top_speed);
+    /// ```
+    pub fn top_speed(&self, env: &Environment) -> f64 {
+        let mut v = 1.0;
+        for _ in 0..100 {
+            let f = self.net_force(v, self.max_drive_force, env);
+            // Derivative of drag_force with respect to v is rho*cd*area*v
+            let df_dv = -env.air_density * self.cd * self.frontal_area * v;
+            if df_dv.abs() < f64::EPSILON {
+                break;
+            }
+            let next_v = v - f / df_dv;
+            if (next_v - v).abs() < 1e-9 {
+                v = next_v;
+                break;
+            }
+            v = next_v.max(0.0);
+        }
+        v
+    }
+
+    /// Euler-integrates acceleration in fixed timesteps of `dt` seconds until
+    /// `target` speed (m/s) is reached, returning the elapsed time in seconds.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::dynamics::{Vehicle, Environment};
+    ///
+    /// let vehicle = Vehicle {
+    ///     mass: 1500.0,
+    ///     frontal_area: 2.2,
+    ///     cd: 0.30,
+    ///     crr: 0.015,
+    ///     max_drive_force: 6000.0,
+    /// };
+    /// let env = Environment::new();
+    /// let time = vehicle.time_to_speed(26.8224, 0.01, &env);
+    /// println!("0-60 in {} seconds", time);
+    /// ```
+    pub fn time_to_speed(&self, target: f64, dt: f64, env: &Environment) -> f64 {
+        let mut v = 0.0;
+        let mut t = 0.0;
+        while v < target {
+            let a = self.acceleration(v, env);
+            if a <= 0.0 {
+                return f64::INFINITY;
+            }
+            v += a * dt;
+            t += dt;
+        }
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_vehicle() -> Vehicle {
+        Vehicle {
+            mass: 1500.0,
+            frontal_area: 2.2,
+            cd: 0.30,
+            crr: 0.015,
+            max_drive_force: 6000.0,
+        }
+    }
+
+    #[test]
+    fn test_drag_force_zero_at_zero_speed() {
+        let vehicle = test_vehicle();
+        let env = Environment::new();
+        assert_eq!(vehicle.drag_force(0.0, &env), 0.0);
+    }
+
+    #[test]
+    fn test_acceleration_positive_at_standstill() {
+        let vehicle = test_vehicle();
+        let env = Environment::new();
+        assert!(vehicle.acceleration(0.0, &env) > 0.0);
+    }
+
+    #[test]
+    fn test_top_speed_has_zero_net_force() {
+        let vehicle = test_vehicle();
+        let env = Environment::new();
+        let top_speed = vehicle.top_speed(&env);
+        assert!(vehicle.net_force(top_speed, vehicle.max_drive_force, &env).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_time_to_speed_increases_with_target() {
+        let vehicle = test_vehicle();
+        let env = Environment::new();
+        let t_30 = vehicle.time_to_speed(13.4112, 0.01, &env);
+        let t_60 = vehicle.time_to_speed(26.8224, 0.01, &env);
+        assert!(t_60 > t_30);
+    }
+}
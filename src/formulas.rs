@@ -1,5 +1,39 @@
 //! Formulas useful for vehicles
 
+/// The ratio of a circle's circumference to its diameter
+pub const PI: f64 = std::f64::consts::PI;
+
+/// Euler's number
+pub const E: f64 = std::f64::consts::E;
+
+/// Converts degrees to radians
+///
+/// example:
+///
+/// ```
+/// use vehicle::formulas::to_radians;
+///
+/// let degrees = 180.0;
+/// let radians = to_radians(degrees);
+/// ```
+pub fn to_radians(deg: f64) -> f64 {
+    deg.to_radians()
+}
+
+/// Converts radians to degrees
+///
+/// example:
+///
+/// ```
+/// use vehicle::formulas::to_degrees;
+///
+/// let radians = std::f64::consts::PI;
+/// let degrees = to_degrees(radians);
+/// ```
+pub fn to_degrees(rad: f64) -> f64 {
+    rad.to_degrees()
+}
+
 /// Converts inches to centimeters
 ///
 /// example:
@@ -233,6 +267,16 @@ mod tests {
         assert_eq!(oss, 1000.0);
     }
 
+    #[test]
+    fn test_to_radians() {
+        assert_eq!(to_radians(180.0), PI);
+    }
+
+    #[test]
+    fn test_to_degrees() {
+        assert_eq!(to_degrees(PI), 180.0);
+    }
+
     #[test]
     fn test_horsepower_and_torque() {
         for hp in 1..1000 {
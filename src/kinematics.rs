@@ -0,0 +1,137 @@
+//! Bicycle (Ackermann) kinematic model for turning radius and pose integration
+use crate::slope::Point;
+
+/// Normalizes an angle in radians to `[-π, π]`.
+fn normalize_heading(heading: f64) -> f64 {
+    let mut h = heading % (2.0 * std::f64::consts::PI);
+    if h > std::f64::consts::PI {
+        h -= 2.0 * std::f64::consts::PI;
+    } else if h < -std::f64::consts::PI {
+        h += 2.0 * std::f64::consts::PI;
+    }
+    h
+}
+
+/// A vehicle's pose: position and heading.
+///
+/// `heading` is in radians, normalized to `[-π, π]`.
+#[derive(Debug, Clone, Copy)]
+pub struct Pose {
+    pub x: f64,
+    pub y: f64,
+    pub heading: f64,
+}
+
+impl Pose {
+    pub fn new(x: f64, y: f64, heading: f64) -> Self {
+        Self {
+            x,
+            y,
+            heading: normalize_heading(heading),
+        }
+    }
+
+    /// Returns the pose's position as a `slope::Point`.
+    pub fn position(&self) -> Point {
+        Point::from(self.x, self.y)
+    }
+}
+
+/// An Ackermann/bicycle model of vehicle steering.
+///
+/// example:
+///
+/// ```
+/// use vehicle::kinematics::{BicycleModel, Pose};
+///
+/// let model = BicycleModel { wheelbase: 2.7 };
+/// let pose = Pose::new(0.0, 0.0, 0.0);
+/// let next = model.step(&pose, 10.0, 0.1, 0.1);
+/// println!("{:?}", next);
+/// ```
+pub struct BicycleModel {
+    /// Distance between the front and rear axles, in meters
+    pub wheelbase: f64,
+}
+
+impl BicycleModel {
+    /// Returns the turning radius for a given steer angle (radians).
+    ///
+    /// Returns `f64::INFINITY` when `steer_angle` is ~0 (driving straight).
+    pub fn turn_radius(&self, steer_angle: f64) -> f64 {
+        if steer_angle.abs() < 1e-9 {
+            return f64::INFINITY;
+        }
+        self.wheelbase / steer_angle.tan()
+    }
+
+    /// Returns the path curvature (1/radius) for a given steer angle (radians).
+    pub fn curvature(&self, steer_angle: f64) -> f64 {
+        steer_angle.tan() / self.wheelbase
+    }
+
+    /// Integrates motion over one timestep of `dt` seconds, given `speed`
+    /// (m/s) and `steer_angle` (radians).
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::kinematics::{BicycleModel, Pose};
+    ///
+    /// let model = BicycleModel { wheelbase: 2.7 };
+    /// let pose = Pose::new(0.0, 0.0, 0.0);
+    /// let next = model.step(&pose, 10.0, 0.0, 1.0);
+    /// assert_eq!(next.x, 10.0);
+    /// assert_eq!(next.y, 0.0);
+    /// ```
+    pub fn step(&self, pose: &Pose, speed: f64, steer_angle: f64, dt: f64) -> Pose {
+        let x = pose.x + speed * pose.heading.cos() * dt;
+        let y = pose.y + speed * pose.heading.sin() * dt;
+        let heading = pose.heading + self.curvature(steer_angle) * speed * dt;
+
+        Pose::new(x, y, heading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_radius_straight_is_infinite() {
+        let model = BicycleModel { wheelbase: 2.7 };
+        assert_eq!(model.turn_radius(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_turn_radius_matches_curvature() {
+        let model = BicycleModel { wheelbase: 2.7 };
+        let steer = 0.3;
+        assert!((model.turn_radius(steer) - 1.0 / model.curvature(steer)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_step_straight_line() {
+        let model = BicycleModel { wheelbase: 2.7 };
+        let pose = Pose::new(0.0, 0.0, 0.0);
+        let next = model.step(&pose, 10.0, 0.0, 1.0);
+        assert_eq!(next.x, 10.0);
+        assert_eq!(next.y, 0.0);
+        assert_eq!(next.heading, 0.0);
+    }
+
+    #[test]
+    fn test_step_turns_heading() {
+        let model = BicycleModel { wheelbase: 2.7 };
+        let pose = Pose::new(0.0, 0.0, 0.0);
+        let next = model.step(&pose, 10.0, 0.2, 1.0);
+        assert!(next.heading > 0.0);
+    }
+
+    #[test]
+    fn test_heading_normalizes() {
+        let pose = Pose::new(0.0, 0.0, 4.0);
+        assert!(pose.heading < std::f64::consts::PI);
+        assert!(pose.heading > -std::f64::consts::PI);
+    }
+}
@@ -1,6 +1,7 @@
 //! Used for calculating the slope of a line
+use std::ops::{Add, Mul, Sub};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 /// Represents a point on an X, Y plane/graph.
 /// 
 /// example:
@@ -32,8 +33,108 @@ impl Point {
             y,
         }
     }
+
+    /// Returns the dot product of this point (as a vector from the origin)
+    /// and another.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Point;
+    ///
+    /// let a = Point::from(1.0, 2.0);
+    /// let b = Point::from(3.0, 4.0);
+    /// assert_eq!(a.dot(&b), 11.0);
+    /// ```
+    pub fn dot(&self, other: &Point) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the magnitude (length) of this point as a vector from the origin.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Point;
+    ///
+    /// let p = Point::from(3.0, 4.0);
+    /// assert_eq!(p.magnitude(), 5.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns a unit vector in the same direction as this point.
+    pub fn normalize(&self) -> Point {
+        let m = self.magnitude();
+        Point::from(self.x / m, self.y / m)
+    }
+
+    /// Returns the Euclidean distance between this point and another.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Point;
+    ///
+    /// let a = Point::from(0.0, 0.0);
+    /// let b = Point::from(3.0, 4.0);
+    /// assert_eq!(a.distance(&b), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Point) -> f64 {
+        (*self - *other).magnitude()
+    }
+
+    /// Returns the component of this point (as a vector) projected onto `other`.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Point;
+    ///
+    /// let a = Point::from(2.0, 2.0);
+    /// let b = Point::from(1.0, 0.0);
+    /// let projection = a.project_on(&b);
+    /// assert_eq!(projection.x, 2.0);
+    /// assert_eq!(projection.y, 0.0);
+    /// ```
+    pub fn project_on(&self, other: &Point) -> Point {
+        *other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Returns the angle in radians between this point and another, via `atan2`.
+    pub fn angle_between(&self, other: &Point) -> f64 {
+        let cross = self.x * other.y - self.y * other.x;
+        let dot = self.dot(other);
+        cross.atan2(dot)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point::from(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point::from(self.x - other.x, self.y - other.y)
+    }
 }
 
+impl Mul<f64> for Point {
+    type Output = Point;
+    fn mul(self, scalar: f64) -> Point {
+        Point::from(self.x * scalar, self.y * scalar)
+    }
+}
+
+/// A 2D vector, as an alias of `Point` for use in contexts where direction
+/// and magnitude matter more than position.
+pub type Vector2 = Point;
+
 #[derive(Debug)]
 /// Slope Formula (m = (y2 - y1)/(x2 - x1) = Δy/Δx)
 /// 
@@ -108,4 +209,96 @@ impl Slope {
         };
         (m, point)
     }
+
+    /// Returns the length of the line segment from `start` to `end`.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Slope;
+    ///
+    /// let slope = Slope::from(0.0, 0.0, 3.0, 4.0);
+    /// assert_eq!(slope.length(), 5.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.start.distance(&self.end)
+    }
+
+    /// Returns the inclination angle of the line in radians, via `atan2(Δy, Δx)`.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::slope::Slope;
+    ///
+    /// let slope = Slope::from(0.0, 0.0, 1.0, 1.0);
+    /// assert_eq!(slope.angle(), std::f64::consts::FRAC_PI_4);
+    /// ```
+    pub fn angle(&self) -> f64 {
+        let dy = self.end.y - self.start.y;
+        let dx = self.end.x - self.start.x;
+        dy.atan2(dx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_dot_and_magnitude() {
+        let a = Point::from(1.0, 2.0);
+        let b = Point::from(3.0, 4.0);
+        assert_eq!(a.dot(&b), 11.0);
+        assert_eq!(b.magnitude(), 5.0);
+    }
+
+    #[test]
+    fn test_point_operators() {
+        let a = Point::from(1.0, 2.0);
+        let b = Point::from(3.0, 4.0);
+        let sum = a + b;
+        assert_eq!(sum.x, 4.0);
+        assert_eq!(sum.y, 6.0);
+
+        let diff = b - a;
+        assert_eq!(diff.x, 2.0);
+        assert_eq!(diff.y, 2.0);
+
+        let scaled = a * 2.0;
+        assert_eq!(scaled.x, 2.0);
+        assert_eq!(scaled.y, 4.0);
+    }
+
+    #[test]
+    fn test_point_distance() {
+        let a = Point::from(0.0, 0.0);
+        let b = Point::from(3.0, 4.0);
+        assert_eq!(a.distance(&b), 5.0);
+    }
+
+    #[test]
+    fn test_point_project_on() {
+        let a = Point::from(2.0, 2.0);
+        let b = Point::from(1.0, 0.0);
+        let projection = a.project_on(&b);
+        assert_eq!(projection.x, 2.0);
+        assert_eq!(projection.y, 0.0);
+    }
+
+    #[test]
+    fn test_point_angle_between() {
+        let a = Point::from(1.0, 0.0);
+        let b = Point::from(0.0, 1.0);
+        assert_eq!(a.angle_between(&b), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_slope_length_and_angle() {
+        let slope = Slope::from(0.0, 0.0, 3.0, 4.0);
+        assert_eq!(slope.length(), 5.0);
+
+        let diagonal = Slope::from(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(diagonal.angle(), std::f64::consts::FRAC_PI_4);
+    }
 }
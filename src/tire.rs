@@ -3,20 +3,95 @@ use regex::Regex;
 
 use crate::formulas;
 
+/// Standard ISO load index to max load (kg) table, covering the range
+/// commonly seen on passenger and light-truck tires.
+const LOAD_INDEX_KG: &[(u32, u32)] = &[
+    (70, 335), (71, 345), (72, 355), (73, 365), (74, 375),
+    (75, 387), (76, 400), (77, 412), (78, 425), (79, 437),
+    (80, 450), (81, 462), (82, 475), (83, 487), (84, 500),
+    (85, 515), (86, 530), (87, 545), (88, 560), (89, 580),
+    (90, 600), (91, 615), (92, 630), (93, 650), (94, 670),
+    (95, 690), (96, 710), (97, 730), (98, 750), (99, 775),
+    (100, 800), (101, 825), (102, 850), (103, 875), (104, 900),
+    (105, 925), (106, 950), (107, 975), (108, 1000), (109, 1030),
+    (110, 1060), (111, 1090), (112, 1120), (113, 1150), (114, 1180),
+    (115, 1215), (116, 1250), (117, 1285), (118, 1320), (119, 1360),
+    (120, 1400), (121, 1450), (122, 1500), (123, 1550), (124, 1600),
+    (125, 1650), (126, 1700),
+];
+
+/// Converts a load index (e.g. `113`) into a max load in Newtons.
+fn load_index_to_newtons(load_index: u32) -> Option<f64> {
+    LOAD_INDEX_KG
+        .iter()
+        .find(|(index, _)| *index == load_index)
+        .map(|(_, kg)| *kg as f64 * 9.80665)
+}
+
+/// The current physical condition of a tire, separate from its nominal spec.
+#[derive(Debug, Clone, Copy)]
+pub struct Condition {
+    /// Current tread depth in mm
+    pub tread_depth_mm: f64,
+    /// Tread depth of a brand-new tire of this model, in mm
+    pub new_tread_depth_mm: f64,
+}
+
+impl Condition {
+    /// A typical new tire starts around 10mm of tread depth.
+    pub fn new() -> Self {
+        Self {
+            tread_depth_mm: 10.0,
+            new_tread_depth_mm: 10.0,
+        }
+    }
+
+    /// Returns a grip derating factor in `[0.5, 1.0]` based on remaining tread.
+    ///
+    /// Grip falls off linearly as tread approaches zero, bottoming out at
+    /// half of a new tire's grip once only the cords remain (tread depth at
+    /// or below zero). The ramp is continuous: it approaches, rather than
+    /// jumps to, that 0.5 floor.
+    pub fn tread_factor(&self) -> f64 {
+        let ratio = (self.tread_depth_mm / self.new_tread_depth_mm).clamp(0.0, 1.0);
+        0.5 + 0.5 * ratio
+    }
+}
+
+impl Default for Condition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct Tire {
     /// Diameter of the tire in inches
     pub diameter: f64,
+    /// Rolling-resistance coefficient
+    pub roll_cof: f64,
+    /// Base grip factor: the max lateral/longitudinal g the tire can produce
+    pub g_factor: f64,
+    /// Current physical condition (tread depth)
+    pub condition: Condition,
+    /// Current load on the tire in Newtons
+    pub load_n: f64,
+    /// Max load rating in Newtons, parsed from the load index if present
+    pub max_load_n: Option<f64>,
 }
 
 impl Tire {
     /// Create a new Tire based on the metric string of the tire.
     ///
+    /// Accepts an optional load-index/speed-rating suffix (e.g. `"113T"`)
+    /// which populates `max_load_n` when the load index is in the 70-126
+    /// table; any other load index leaves `max_load_n` as `None`.
+    ///
     /// example:
     ///
     /// ```
     /// use vehicle::tire::Tire;
     ///
-    /// let tire = Tire::new("275/55R20");
+    /// let tire = Tire::new("275/55R20 113T");
     ///
     /// println!("{}\" diameter {}\" Circumference", tire.diameter, tire.circumference());
     /// ```
@@ -35,7 +110,44 @@ impl Tire {
         let height_mm = (width * (aspect_ratio / 100.0)) * 2.0;
         let diameter = formulas::to_in(height_mm / 10.0) + wheel_diameter;
 
-        Tire { diameter }
+        let load_speed_re = Regex::new(r"(\d{2,3})[A-Z]$").unwrap();
+        let max_load_n = load_speed_re
+            .captures(value.trim())
+            .and_then(|caps| caps.get(1))
+            .and_then(|m| m.as_str().parse::<u32>().ok())
+            .and_then(load_index_to_newtons);
+
+        Tire {
+            diameter,
+            roll_cof: 0.01,
+            g_factor: 1.0,
+            condition: Condition::new(),
+            load_n: 0.0,
+            max_load_n,
+        }
+    }
+
+    /// Returns the maximum lateral/longitudinal grip force in Newtons the
+    /// tire can produce under the given load.
+    ///
+    /// This is the tire's rated grip; use `condition.tread_factor()`
+    /// separately to derate it for wear.
+    pub fn max_grip_force(&self, load_n: f64) -> f64 {
+        self.g_factor * load_n
+    }
+
+    /// Returns the rolling-resistance force in Newtons under the given load.
+    pub fn rolling_resistance(&self, load_n: f64) -> f64 {
+        self.roll_cof * load_n
+    }
+
+    /// Wears the tire's tread based on distance driven (miles) and a driving
+    /// severity multiplier (1.0 is normal driving; higher values wear faster).
+    pub fn wear(&mut self, distance_miles: f64, severity: f64) {
+        // A rough rule of thumb: ~1mm of tread lost per ~10,000 miles of
+        // normal (severity 1.0) driving.
+        let wear_mm = (distance_miles / 10_000.0) * severity;
+        self.condition.tread_depth_mm = (self.condition.tread_depth_mm - wear_mm).max(0.0);
     }
 
     /// Returns the circumference of the Tire based on the diameter.
@@ -106,4 +218,47 @@ mod tests {
         assert_eq!(tire.miles_per_rev(), 0.001581371043108248);
         assert_eq!(tire.revs_per_mile(), 632.362660463581);
     }
+
+    #[test]
+    fn test_tire_max_load_parsing() {
+        let tire = Tire::new("275/55R20 113T");
+        assert_eq!(tire.max_load_n, Some(1150.0 * 9.80665));
+
+        let tire_no_rating = Tire::new("275/55R20");
+        assert_eq!(tire_no_rating.max_load_n, None);
+    }
+
+    #[test]
+    fn test_tire_grip_and_rolling_resistance() {
+        let tire = Tire::new("275/55R20 113T");
+        assert_eq!(tire.max_grip_force(1000.0), tire.g_factor * 1000.0);
+        assert_eq!(tire.rolling_resistance(1000.0), tire.roll_cof * 1000.0);
+    }
+
+    #[test]
+    fn test_tire_wear_derates_tread_factor() {
+        let mut tire = Tire::new("275/55R20 113T");
+        let full_factor = tire.condition.tread_factor();
+        assert_eq!(full_factor, 1.0);
+
+        tire.wear(50_000.0, 1.0);
+        assert!(tire.condition.tread_depth_mm < 10.0);
+        assert!(tire.condition.tread_factor() < full_factor);
+        assert!(tire.condition.tread_factor() > 0.5);
+
+        tire.wear(1_000_000.0, 1.0);
+        assert_eq!(tire.condition.tread_depth_mm, 0.0);
+        assert_eq!(tire.condition.tread_factor(), 0.5);
+    }
+
+    #[test]
+    fn test_tread_factor_is_monotonic_and_continuous_at_zero() {
+        let mut condition = Condition::new();
+        condition.tread_depth_mm = 0.01;
+        let just_above_zero = condition.tread_factor();
+        condition.tread_depth_mm = 0.0;
+        let at_zero = condition.tread_factor();
+        assert!(just_above_zero >= at_zero);
+        assert!((just_above_zero - at_zero).abs() < 1e-3);
+    }
 }
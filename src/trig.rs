@@ -3,6 +3,15 @@
 
 //use std::ops::{Add, Div, Mul, Sub, AddAssign, MulAssign, DivAssign, SubAssign};
 
+use crate::formulas;
+
+/// Whether a `Function`'s input/output angles are in radians or degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleMode {
+    Radians,
+    Degrees,
+}
+
 /// Transformations for Trigonometry Functions
 #[derive(Debug)]
 pub struct Function {
@@ -11,6 +20,7 @@ pub struct Function {
     b: f64,
     c: f64,
     d: f64,
+    angle_mode: AngleMode,
 }
 
 impl Function {
@@ -49,32 +59,73 @@ impl Function {
             b,
             c,
             d,
+            angle_mode: AngleMode::Radians,
+        }
+    }
+
+    /// Builder that sets the angle mode to degrees.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::trig::Function;
+    ///
+    /// let function = Function::new(90.0, None, None, None, None).with_degrees();
+    /// assert!((function.sin() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn with_degrees(mut self) -> Self {
+        self.angle_mode = AngleMode::Degrees;
+        self
+    }
+
+    /// Sets the angle mode (radians or degrees).
+    pub fn set_angle_mode(&mut self, mode: AngleMode) {
+        self.angle_mode = mode;
+    }
+
+    /// Computes `Bx - C` as an angle, converting from degrees to radians
+    /// first if the function is in `AngleMode::Degrees`.
+    fn angle_argument(&self) -> f64 {
+        let arg = self.b * self.x - self.c;
+        match self.angle_mode {
+            AngleMode::Radians => arg,
+            AngleMode::Degrees => formulas::to_radians(arg),
+        }
+    }
+
+    /// Converts an inverse trig function's radian result back to the
+    /// function's angle mode.
+    fn to_angle_mode(&self, value: f64) -> f64 {
+        match self.angle_mode {
+            AngleMode::Radians => value,
+            AngleMode::Degrees => formulas::to_degrees(value),
         }
     }
 
     pub fn sin(&self) -> f64 {
         // y = A sin(Bx - C) + D
-        self.a * (self.b*self.x-self.c).sin() + self.d
+        self.a * self.angle_argument().sin() + self.d
     }
 
     pub fn cos(&self) -> f64 {
-        self.a * (self.b*self.x-self.c).cos() + self.d
+        self.a * self.angle_argument().cos() + self.d
     }
 
     pub fn tan(&self) -> f64 {
-        self.a * (self.b*self.x-self.c).tan() + self.d
+        self.a * self.angle_argument().tan() + self.d
     }
 
     pub fn asin(&self) -> f64 {
-        self.a * (self.b*self.x-self.c).asin() + self.d
+        // Bx - C here is a ratio, not an angle, so it is not converted.
+        self.a * self.to_angle_mode((self.b * self.x - self.c).asin()) + self.d
     }
 
     pub fn acos(&self) -> f64 {
-        self.a * (self.b*self.x-self.c).acos() + self.d
+        self.a * self.to_angle_mode((self.b * self.x - self.c).acos()) + self.d
     }
 
     pub fn atan(&self) -> f64 {
-        self.a * (self.b*self.x-self.c).atan() + self.d
+        self.a * self.to_angle_mode((self.b * self.x - self.c).atan()) + self.d
     }
 
     /// Sets the Amplitude (a) of the function. (y = A sin(Bx - C) + D)
@@ -131,4 +182,23 @@ mod tests {
         //assert_eq!(function.acos(), 2.0_f64.acos());
         //assert_eq!(function.atan(), 2.0_f64.atan());
     }
+
+    #[test]
+    fn test_degrees_mode_sin() {
+        let function = Function::new(90.0, None, None, None, None).with_degrees();
+        assert!((function.sin() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_degrees_mode_asin_returns_degrees() {
+        let function = Function::new(1.0, None, None, None, None).with_degrees();
+        assert!((function.asin() - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_set_angle_mode() {
+        let mut function = Function::new(90.0, None, None, None, None);
+        function.set_angle_mode(AngleMode::Degrees);
+        assert!((function.sin() - 1.0).abs() < 1e-9);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,384 @@
+//! Strongly-typed physical quantities to prevent mixed-unit bugs
+//!
+//! Each quantity is a newtype over `f64` that stores its value in a single
+//! canonical SI base unit internally (meters, meters/second, radians/second,
+//! Newton-meters, Watts). Constructors and accessors are provided for the
+//! common units used elsewhere in the crate so callers never have to juggle
+//! raw conversion factors by hand.
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::formulas;
+
+/// A length, stored internally in meters.
+///
+/// example:
+///
+/// ```
+/// use vehicle::units::Length;
+///
+/// let length = Length::from_inches(1.0);
+/// assert_eq!(length.as_inches(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length {
+    meters: f64,
+}
+
+impl Length {
+    pub fn from_meters(meters: f64) -> Self {
+        Self { meters }
+    }
+
+    pub fn from_inches(inches: f64) -> Self {
+        Self {
+            meters: formulas::to_cm(inches) / 100.0,
+        }
+    }
+
+    pub fn as_meters(&self) -> f64 {
+        self.meters
+    }
+
+    pub fn as_inches(&self) -> f64 {
+        formulas::to_in(self.meters * 100.0)
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+    fn add(self, other: Length) -> Length {
+        Length::from_meters(self.meters + other.meters)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+    fn sub(self, other: Length) -> Length {
+        Length::from_meters(self.meters - other.meters)
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Length;
+    fn mul(self, scalar: f64) -> Length {
+        Length::from_meters(self.meters * scalar)
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Length;
+    fn div(self, scalar: f64) -> Length {
+        Length::from_meters(self.meters / scalar)
+    }
+}
+
+/// A speed, stored internally in meters per second.
+///
+/// example:
+///
+/// ```
+/// use vehicle::units::Speed;
+///
+/// let speed = Speed::from_mph(100.0);
+/// println!("{} kph", speed.as_kph());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Speed {
+    meters_per_sec: f64,
+}
+
+impl Speed {
+    pub fn from_meters_per_sec(meters_per_sec: f64) -> Self {
+        Self { meters_per_sec }
+    }
+
+    pub fn from_mph(mph: f64) -> Self {
+        Speed::from_kph(formulas::to_kph(mph))
+    }
+
+    pub fn from_kph(kph: f64) -> Self {
+        Self {
+            meters_per_sec: kph * 1000.0 / 3600.0,
+        }
+    }
+
+    pub fn as_meters_per_sec(&self) -> f64 {
+        self.meters_per_sec
+    }
+
+    pub fn as_kph(&self) -> f64 {
+        self.meters_per_sec * 3600.0 / 1000.0
+    }
+
+    pub fn as_mph(&self) -> f64 {
+        // Derived directly from the canonical m/s value rather than composing
+        // to_kph/to_mph, whose rounded factors are not exact inverses.
+        self.meters_per_sec / 1609.344 * 3600.0
+    }
+
+    /// Calculates mph from oss (Output Shaft Speed behind transmission), returning a typed `Speed`.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::units::Speed;
+    ///
+    /// let speed = Speed::from_oss(1000.0, 600.0, 3.21);
+    /// ```
+    pub fn from_oss(oss: f64, tire_revs_per_mile: f64, axle_ratio: f64) -> Self {
+        Speed::from_mph(formulas::mph_from_oss(oss, tire_revs_per_mile, axle_ratio))
+    }
+
+    /// Calculates the oss (Output Shaft Speed behind transmission) for this speed.
+    ///
+    /// example:
+    ///
+    /// ```
+    /// use vehicle::units::Speed;
+    ///
+    /// let oss = Speed::from_mph(100.0).to_oss(600.0, 3.21);
+    /// ```
+    pub fn to_oss(&self, tire_revs_per_mile: f64, axle_ratio: f64) -> f64 {
+        formulas::oss_from_mph(self.as_mph(), tire_revs_per_mile, axle_ratio)
+    }
+}
+
+impl Add for Speed {
+    type Output = Speed;
+    fn add(self, other: Speed) -> Speed {
+        Speed::from_meters_per_sec(self.meters_per_sec + other.meters_per_sec)
+    }
+}
+
+impl Sub for Speed {
+    type Output = Speed;
+    fn sub(self, other: Speed) -> Speed {
+        Speed::from_meters_per_sec(self.meters_per_sec - other.meters_per_sec)
+    }
+}
+
+impl Mul<f64> for Speed {
+    type Output = Speed;
+    fn mul(self, scalar: f64) -> Speed {
+        Speed::from_meters_per_sec(self.meters_per_sec * scalar)
+    }
+}
+
+impl Div<f64> for Speed {
+    type Output = Speed;
+    fn div(self, scalar: f64) -> Speed {
+        Speed::from_meters_per_sec(self.meters_per_sec / scalar)
+    }
+}
+
+/// An angular speed, stored internally in radians per second.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct AngularSpeed {
+    radians_per_sec: f64,
+}
+
+impl AngularSpeed {
+    pub fn from_radians_per_sec(radians_per_sec: f64) -> Self {
+        Self { radians_per_sec }
+    }
+
+    pub fn from_rpm(rpm: f64) -> Self {
+        Self {
+            radians_per_sec: rpm * 2.0 * std::f64::consts::PI / 60.0,
+        }
+    }
+
+    pub fn as_radians_per_sec(&self) -> f64 {
+        self.radians_per_sec
+    }
+
+    pub fn as_rpm(&self) -> f64 {
+        self.radians_per_sec * 60.0 / (2.0 * std::f64::consts::PI)
+    }
+}
+
+impl Add for AngularSpeed {
+    type Output = AngularSpeed;
+    fn add(self, other: AngularSpeed) -> AngularSpeed {
+        AngularSpeed::from_radians_per_sec(self.radians_per_sec + other.radians_per_sec)
+    }
+}
+
+impl Sub for AngularSpeed {
+    type Output = AngularSpeed;
+    fn sub(self, other: AngularSpeed) -> AngularSpeed {
+        AngularSpeed::from_radians_per_sec(self.radians_per_sec - other.radians_per_sec)
+    }
+}
+
+impl Mul<f64> for AngularSpeed {
+    type Output = AngularSpeed;
+    fn mul(self, scalar: f64) -> AngularSpeed {
+        AngularSpeed::from_radians_per_sec(self.radians_per_sec * scalar)
+    }
+}
+
+impl Div<f64> for AngularSpeed {
+    type Output = AngularSpeed;
+    fn div(self, scalar: f64) -> AngularSpeed {
+        AngularSpeed::from_radians_per_sec(self.radians_per_sec / scalar)
+    }
+}
+
+/// A torque, stored internally in Newton-meters.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Torque {
+    newton_meters: f64,
+}
+
+impl Torque {
+    pub fn from_newton_meters(newton_meters: f64) -> Self {
+        Self { newton_meters }
+    }
+
+    pub fn from_ft_lbs(ft_lbs: f64) -> Self {
+        Self {
+            newton_meters: ft_lbs * 1.355818,
+        }
+    }
+
+    pub fn as_newton_meters(&self) -> f64 {
+        self.newton_meters
+    }
+
+    pub fn as_ft_lbs(&self) -> f64 {
+        self.newton_meters / 1.355818
+    }
+}
+
+impl Add for Torque {
+    type Output = Torque;
+    fn add(self, other: Torque) -> Torque {
+        Torque::from_newton_meters(self.newton_meters + other.newton_meters)
+    }
+}
+
+impl Sub for Torque {
+    type Output = Torque;
+    fn sub(self, other: Torque) -> Torque {
+        Torque::from_newton_meters(self.newton_meters - other.newton_meters)
+    }
+}
+
+impl Mul<f64> for Torque {
+    type Output = Torque;
+    fn mul(self, scalar: f64) -> Torque {
+        Torque::from_newton_meters(self.newton_meters * scalar)
+    }
+}
+
+impl Div<f64> for Torque {
+    type Output = Torque;
+    fn div(self, scalar: f64) -> Torque {
+        Torque::from_newton_meters(self.newton_meters / scalar)
+    }
+}
+
+/// A power, stored internally in Watts.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Power {
+    watts: f64,
+}
+
+impl Power {
+    pub fn from_watts(watts: f64) -> Self {
+        Self { watts }
+    }
+
+    pub fn from_hp(hp: f64) -> Self {
+        Self {
+            watts: hp * 745.699872,
+        }
+    }
+
+    pub fn as_watts(&self) -> f64 {
+        self.watts
+    }
+
+    pub fn as_hp(&self) -> f64 {
+        self.watts / 745.699872
+    }
+}
+
+impl Add for Power {
+    type Output = Power;
+    fn add(self, other: Power) -> Power {
+        Power::from_watts(self.watts + other.watts)
+    }
+}
+
+impl Sub for Power {
+    type Output = Power;
+    fn sub(self, other: Power) -> Power {
+        Power::from_watts(self.watts - other.watts)
+    }
+}
+
+impl Mul<f64> for Power {
+    type Output = Power;
+    fn mul(self, scalar: f64) -> Power {
+        Power::from_watts(self.watts * scalar)
+    }
+}
+
+impl Div<f64> for Power {
+    type Output = Power;
+    fn div(self, scalar: f64) -> Power {
+        Power::from_watts(self.watts / scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_round_trip() {
+        let length = Length::from_inches(10.0);
+        assert!((length.as_inches() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_speed_mph_kph() {
+        let speed = Speed::from_mph(100.0);
+        assert!((speed.as_kph() - 160.9344).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_speed_from_oss_matches_formulas() {
+        let speed = Speed::from_oss(1000.0, 600.0, 3.21);
+        let expected = formulas::mph_from_oss(1000.0, 600.0, 3.21);
+        assert!((speed.as_mph() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_speed_operators() {
+        let a = Speed::from_mph(50.0);
+        let b = Speed::from_mph(25.0);
+        assert!(((a - b).as_mph() - 25.0).abs() < 1e-9);
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_angular_speed_rpm() {
+        let speed = AngularSpeed::from_rpm(60.0);
+        assert!((speed.as_radians_per_sec() - 2.0 * std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_torque_ft_lbs() {
+        let torque = Torque::from_ft_lbs(1.0);
+        assert!((torque.as_ft_lbs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_power_hp() {
+        let power = Power::from_hp(350.0);
+        assert!((power.as_hp() - 350.0).abs() < 1e-6);
+    }
+}